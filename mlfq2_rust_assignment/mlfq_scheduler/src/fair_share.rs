@@ -0,0 +1,173 @@
+// Accumulated-priority fair-share scheduling: every ready process accrues a contribution based
+// on its base `priority` each round it waits, and the process with the most accumulated priority
+// runs next. Running costs priority -- a process is charged `priority_credit` for the slots it
+// has run, deducted from its `accumulated_priority` once it yields. This guarantees starvation
+// freedom: a process that keeps losing out keeps accruing credit, and eventually out-accrues
+// everyone else.
+//
+// Deliberately single-core: `Scheduler::next` returns at most one `Option<Process>`, so exactly
+// one process is dispatched per round through this trait, and every other policy here (MLFQ,
+// RoundRobin, Fcfs, Sjf) is single-winner-per-round too. `cores` only divides `credit_per_slot`
+// (a slot is cheaper per process when more cores are sharing the work) -- it does not select
+// multiple winners per round, so it's a cost-scaling knob, not a selection-width knob. Genuine
+// multi-core fair-share (picking the top `cores` processes at once) would need a `Scheduler`
+// whose `next` can return more than one process at a time, which would mean widening the trait
+// for every implementor -- out of scope for this scheduler alone.
+use crate::mlfq::Process;
+use crate::scheduler::Scheduler;
+
+pub struct FairShare {
+    ready: Vec<Process>,
+    cores: u32,       // number of CPUs being fair-shared; divides the credit a slot costs (see module doc)
+    capacity: i64,     // baseline cost of a full slot on a single core
+    multiplier: i64,   // scales how aggressively a slot is charged against accumulated_priority
+}
+
+impl FairShare {
+    pub fn new(cores: u32, capacity: i64, multiplier: i64) -> Self {
+        FairShare { ready: Vec::new(), cores, capacity, multiplier }
+    }
+
+    // The priority charged for one slot, spread across `cores` -- more cores means each slot
+    // is cheaper per process, since more processes can run at once.
+    fn credit_per_slot(&self) -> i64 {
+        (self.capacity * self.multiplier) / (self.cores.max(1) as i64)
+    }
+}
+
+impl Scheduler for FairShare {
+    fn add_process(&mut self, process: Process) {
+        self.ready.push(process);
+    }
+
+    fn next(&mut self) -> Option<Process> {
+        // Every process waiting its turn accrues its base-priority contribution (plus a +1
+        // floor so a priority-0 process still rises and can't starve forever), then the one
+        // with the most accumulated priority runs -- the classic accumulated-priority /
+        // fair-share selection rule.
+        for process in &mut self.ready {
+            process.accumulated_priority += process.priority as i64 + 1;
+        }
+
+        let winner_index = self.ready.iter()
+            .enumerate()
+            .max_by_key(|(_, p)| p.accumulated_priority)
+            .map(|(index, _)| index)?;
+
+        let mut process = self.ready.remove(winner_index);
+        // Accumulate rather than overwrite: a process that keeps winning keeps piling more
+        // credit on top of what it has already been charged, so the cost of hogging the CPU
+        // compounds round over round instead of staying flat.
+        process.priority_credit += self.credit_per_slot();
+        Some(process)
+    }
+
+    fn quantum_for(&self, _process: &Process) -> u32 {
+        // Run for exactly one slot's worth of credit, so on_quantum_expired always fires and
+        // the charge below is applied -- unless the process finishes first, in which case there
+        // is nothing left to charge.
+        self.credit_per_slot().max(1) as u32
+    }
+
+    fn on_quantum_expired(&mut self, mut process: Process) {
+        process.accumulated_priority -= process.priority_credit;
+        self.ready.push(process);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::dispatch;
+
+    fn process(id: u32, priority: usize, remaining_time: u32) -> Process {
+        Process {
+            id, priority, remaining_time, total_executed_time: 0,
+            allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None,
+            accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
+    #[test]
+    fn test_fair_share_prefers_process_with_more_accumulated_priority() {
+        let mut fs = FairShare::new(1, 4, 1);
+        fs.add_process(process(1, 0, 10));
+        fs.add_process(process(2, 0, 10));
+
+        // Give process 2 a head start on accumulated priority before either one has run.
+        fs.ready[1].accumulated_priority = 100;
+
+        assert_eq!(fs.next().unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_fair_share_charges_priority_credit_on_quantum_expiry() {
+        let mut fs = FairShare::new(1, 4, 1);
+        fs.add_process(process(1, 0, 10));
+
+        assert!(dispatch(&mut fs).is_none()); // still has work left, so it's requeued
+        let requeued = &fs.ready[0];
+        // Accrued +1 for waiting during `next`, then charged credit_per_slot() (4) for the slot.
+        assert_eq!(requeued.accumulated_priority, 1 - fs.credit_per_slot());
+    }
+
+    #[test]
+    fn test_fair_share_accrues_by_base_priority() {
+        let mut fs = FairShare::new(1, 4, 1);
+        fs.add_process(process(1, 0, 10)); // base priority 0: accrues the +1 floor only
+        fs.add_process(process(2, 3, 10)); // base priority 3: accrues 3 more per round on top
+
+        // Process 2's higher base priority makes it accrue faster, so it wins the very first
+        // round despite arriving with no head start.
+        let winner = fs.next().unwrap();
+        assert_eq!(winner.id, 2);
+        assert_eq!(winner.accumulated_priority, 4);
+        assert_eq!(fs.ready[0].accumulated_priority, 1); // process 1 only got the +1 floor
+    }
+
+    #[test]
+    fn test_fair_share_returns_completed_process() {
+        let mut fs = FairShare::new(1, 4, 1);
+        fs.add_process(process(1, 0, 2));
+
+        let completed = dispatch(&mut fs).expect("process should have completed");
+        assert_eq!(completed.id, 1);
+        assert_eq!(completed.remaining_time, 0);
+    }
+
+    #[test]
+    fn test_fair_share_no_starvation_for_continuously_ready_low_priority_process() {
+        // Process 1 is a high-base-priority hog: its accrual rate (11/round) dwarfs process 2's
+        // (1/round), so it wins every single round at first -- this is verified below, not just
+        // assumed, since a same-priority tie would let `next()` hand the very first round to
+        // process 2 for free and the anti-starvation mechanism would never actually run. But the
+        // credit charged for each of the hog's wins grows every time it wins (see `next()`), so
+        // its net gain per round shrinks and eventually goes negative while process 2 keeps
+        // accruing +1 every round regardless of who wins -- so process 2 must overtake eventually.
+        let mut fs = FairShare::new(4, 4, 1);
+        fs.add_process(process(1, 10, 1_000_000)); // high base priority, effectively endless work
+        fs.add_process(process(2, 0, 5));           // lowest priority, no head start -- must not starve
+
+        let first_winner = fs.next().expect("a process should be ready");
+        assert_eq!(first_winner.id, 1, "setup invariant: the hog must win round 1, not tie into it");
+        let mut winner = first_winner;
+        winner.remaining_time -= 1;
+        fs.on_quantum_expired(winner);
+
+        let mut process_2_ran = false;
+        for _ in 0..10_000 {
+            if let Some(winner) = fs.next() {
+                if winner.id == 2 {
+                    process_2_ran = true;
+                    break;
+                }
+                // requeue exactly as dispatch() would for an unfinished process
+                let mut winner = winner;
+                winner.remaining_time -= 1;
+                fs.on_quantum_expired(winner);
+            }
+        }
+
+        assert!(process_2_ran, "process 2 should eventually be scheduled, never starved");
+    }
+}
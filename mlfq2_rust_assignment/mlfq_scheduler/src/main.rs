@@ -1,30 +1,69 @@
 // Importing the mlfq module, which contains the MLFQ and Process structs
 mod mlfq;
+mod scheduler;
+mod round_robin;
+mod fcfs;
+mod sjf;
+mod fair_share;
+
+use scheduler::{dispatch, Scheduler};
 
 fn main() {
-    // Create a new MLFQ scheduler with 3 levels and time slices of 2, 4, and 8 units respectively
-    let mut scheduler = mlfq::MLFQ::new(3, vec![2, 4, 8]);
-
-    // Adding processes to the scheduler with their respective attributes
-    scheduler.add_process(mlfq::Process { id: 1, priority: 0, remaining_time: 10, total_executed_time: 0 });
-    scheduler.add_process(mlfq::Process { id: 2, priority: 0, remaining_time: 3, total_executed_time: 0 });
-    scheduler.add_process(mlfq::Process { id: 3, priority: 1, remaining_time: 5, total_executed_time: 0 });
-
-    // Iterate through each queue level in the scheduler
-    for queue_index in 0..scheduler.num_levels {
-        // While there are processes in the current queue level
-        while !scheduler.queues[queue_index].is_empty() {
-            // Execute the next process in the queue
-            scheduler.execute_process(queue_index);
-        }
+    // Create a new MLFQ scheduler with 3 levels, time slices of 2, 4, and 8 units, and
+    // per-level allotments of 4, 8, and 16 units (two quanta's worth) before demotion
+    let mut scheduler = mlfq::MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+
+    // Describe a workload with staggered arrivals and let the scheduler run it to completion
+    let processes = vec![
+        mlfq::Process { id: 1, priority: 0, remaining_time: 10, total_executed_time: 0, allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0 },
+        mlfq::Process { id: 2, priority: 0, remaining_time: 3, total_executed_time: 0, allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0 },
+        mlfq::Process { id: 3, priority: 0, remaining_time: 5, total_executed_time: 0, allotment_used: 0, arrival_time: 4, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0 },
+    ];
+
+    scheduler.run_until_complete(processes);
+
+    // Report per-process and average scheduling quality metrics
+    let metrics = scheduler.metrics();
+    for process_metrics in &metrics.per_process {
+        println!(
+            "Process ID: {}, Turnaround: {}, Waiting: {}, Response: {}",
+            process_metrics.id, process_metrics.turnaround_time,
+            process_metrics.waiting_time, process_metrics.response_time
+        );
     }
+    println!(
+        "Averages -> Turnaround: {:.2}, Waiting: {:.2}, Response: {:.2}",
+        metrics.average_turnaround_time, metrics.average_waiting_time, metrics.average_response_time
+    );
+
+    // Drain the same workload under each pluggable policy through the shared dispatch loop
+    let workload = |s: &mut dyn Scheduler| {
+        s.add_process(mlfq::Process { id: 1, priority: 0, remaining_time: 10, total_executed_time: 0, allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0 });
+        s.add_process(mlfq::Process { id: 2, priority: 0, remaining_time: 3, total_executed_time: 0, allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0 });
+        s.add_process(mlfq::Process { id: 3, priority: 0, remaining_time: 5, total_executed_time: 0, allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0 });
+    };
 
-    // Update the scheduler's time after processing
-    scheduler.update_time(100);
+    let mut round_robin = round_robin::RoundRobin::new(2);
+    let mut fcfs = fcfs::Fcfs::new();
+    let mut sjf = sjf::Sjf::new();
+    let mut fair_share = fair_share::FairShare::new(1, 4, 1);
+    workload(&mut round_robin);
+    workload(&mut fcfs);
+    workload(&mut sjf);
+    workload(&mut fair_share);
 
-    // Print the state of each queue in the scheduler
-    for (index, queue) in scheduler.queues.iter().enumerate() {
-        println!("Queue {}: {:?}", index, queue);
+    for (name, sched) in [
+        ("RoundRobin", &mut round_robin as &mut dyn Scheduler),
+        ("Fcfs", &mut fcfs as &mut dyn Scheduler),
+        ("Sjf", &mut sjf as &mut dyn Scheduler),
+        ("FairShare", &mut fair_share as &mut dyn Scheduler),
+    ] {
+        let mut completed = 0;
+        while completed < 3 {
+            if dispatch(sched).is_some() {
+                completed += 1;
+            }
+        }
+        println!("{} drained the workload", name);
     }
 }
-
@@ -5,27 +5,74 @@ pub struct Process {
     pub priority: usize,                // Represents the current queue index for the process
     pub remaining_time: u32,            // Time left for the process to complete execution
     pub total_executed_time: u32,       // Total time the process has been executed
+    pub allotment_used: u32,            // CPU time consumed at the current priority level so far
+    pub arrival_time: u32,              // Time at which the process becomes ready to run
+    pub completion_time: Option<u32>,   // Time at which the process finished, once known
+    pub first_run_time: Option<u32>,    // Time of the process's first tick on the CPU, once known
+    pub accumulated_priority: i64,      // Fair-share credit built up while waiting (see FairShare)
+    pub priority_credit: i64,           // Fair-share cost accrued across the slots it's been given, charged on yield
 }
 
+use std::collections::VecDeque;
+
+use crate::scheduler::Scheduler;
+
+// The boost interval (in ticks) used both by `update_time` and `run_until_complete` to
+// periodically reset starvation by moving every process back to the top queue.
+const BOOST_INTERVAL: u32 = 100;
+
 // Define the MLFQ scheduler structure
+//
+// `MLFQ` stays all-caps rather than `Mlfq` -- it's the name of the algorithm (as in OSTEP),
+// not a word, and renaming it would ripple through the module name, every call site and test
+// in this file for no behavioral gain. Silence the acronym lint here instead.
+#[allow(clippy::upper_case_acronyms)]
 pub struct MLFQ {
-    pub queues: Vec<Vec<Process>>,      // Vector of queues for each priority level
+    pub queues: Vec<VecDeque<Process>>, // Vector of queues for each priority level, FIFO order
     pub num_levels: usize,               // Total number of priority levels
     time_quanta: Vec<u32>,               // Time slices for each priority level
+    allotment: Vec<u32>,                 // Total CPU time allowed at each level before demotion
     current_time: u32,                   // Tracks the current time in the scheduler
+    completed: Vec<Process>,             // Processes that have finished, kept for metrics()
+    ready_bitmap: u64,                   // Bit i is set iff queues[i] is non-empty
 }
 
 impl MLFQ {
-    // Create a new MLFQ scheduler with specified levels and time quanta
-    pub fn new(num_levels: usize, time_quanta: Vec<u32>) -> Self {
-        // Initialize queues with an empty Vec for each priority level
-        let queues = (0..num_levels).map(|_| Vec::new()).collect();
+    // Create a new MLFQ scheduler with specified levels, time quanta and per-level allotments
+    pub fn new(num_levels: usize, time_quanta: Vec<u32>, allotment: Vec<u32>) -> Self {
+        assert!(num_levels <= 64, "ready_bitmap only has room for 64 priority levels");
+        // Initialize queues with an empty VecDeque for each priority level
+        let queues = (0..num_levels).map(|_| VecDeque::new()).collect();
 
         MLFQ {
             queues,                             // Use the initialized queues
             num_levels,
             time_quanta,
+            allotment,
             current_time: 0,                   // Start the current time at zero
+            completed: Vec::new(),
+            ready_bitmap: 0,
+        }
+    }
+
+    // Keep `ready_bitmap`'s bit for `level` in sync with whether queues[level] is non-empty.
+    // Called after every push/pop so `highest_ready_level` never has to look at the queues.
+    fn sync_ready_bit(&mut self, level: usize) {
+        if self.queues[level].is_empty() {
+            self.ready_bitmap &= !(1 << level);
+        } else {
+            self.ready_bitmap |= 1 << level;
+        }
+    }
+
+    // The highest-priority (lowest-index) level with a ready process, computed in O(1) via
+    // `trailing_zeros` on the ready bitmap instead of scanning the queues linearly -- the
+    // count-trailing-zeros trick used by real multi-level-queue kernels for O(1) dispatch.
+    pub fn highest_ready_level(&self) -> Option<usize> {
+        if self.ready_bitmap == 0 {
+            None
+        } else {
+            Some(self.ready_bitmap.trailing_zeros() as usize)
         }
     }
 
@@ -33,18 +80,35 @@ impl MLFQ {
     pub fn add_process(&mut self, process: Process) {
         let priority = process.priority;
         // Ensure the process is placed in a valid queue
-        if priority < self.num_levels {
-            self.queues[priority].push(process);
+        let level = if priority < self.num_levels {
+            priority
         } else {
             // If priority is too high, place it in the lowest priority queue
-            self.queues[self.num_levels - 1].push(process);
-        }
+            self.num_levels - 1
+        };
+        self.queues[level].push_back(process);
+        self.sync_ready_bit(level);
     }
 
-    // Execute the next process in the specified queue
+    // Execute the next process in the specified queue for one quantum. A process that still has
+    // allotment left at this level is rotated to the back of the *same* queue instead of being
+    // demoted, so several same-level jobs genuinely round-robin between each other -- this is
+    // the standalone round-robin sweep the MLFQ conversion first introduced as its own method
+    // before per-level allotment tracking folded the bookkeeping in here. The process is demoted
+    // to the next lower queue only once it has consumed its full allotment at this level
+    // (OSTEP Rule 4) -- a job that gives up the CPU early (e.g. for I/O) keeps accumulating
+    // `allotment_used` across calls instead of getting demoted for free, which closes the
+    // classic "yield before the quantum expires to stay at the top forever" gaming hole.
+    //
+    // `run_until_complete` no longer drives the simulation through this (it inlines the same
+    // per-tick bookkeeping instead), but it's kept and still exercised directly by
+    // `test_execute_process_is_fifo` / `test_execute_process_round_robins_within_allotment`,
+    // which test single-level execution semantics in isolation.
+    #[allow(dead_code)]
     pub fn execute_process(&mut self, queue_index: usize) {
-        // Attempt to retrieve the next process in the queue
-        if let Some(mut process) = self.queues[queue_index].pop() {
+        // Attempt to retrieve the next process in the queue, oldest arrival first
+        if let Some(mut process) = self.queues[queue_index].pop_front() {
+            self.sync_ready_bit(queue_index);
             let time_quantum = self.time_quanta[queue_index]; // Get the time quantum for this queue
             // Determine the amount of time to execute
             let executed_time = if process.remaining_time > time_quantum {
@@ -53,23 +117,37 @@ impl MLFQ {
                 process.remaining_time // Execute for the remaining time if it's less
             };
 
-            // Update the process's remaining and total executed time
+            // Update the process's remaining, total executed and level-allotment time
             process.remaining_time -= executed_time;
             process.total_executed_time += executed_time;
+            process.allotment_used += executed_time;
             self.current_time += executed_time; // Update the current time
 
             // Log the execution details
-            println!("Executed Process ID: {}, Time Executed: {}, Time Remaining: {}", 
+            println!("Executed Process ID: {}, Time Executed: {}, Time Remaining: {}",
                      process.id, executed_time, process.remaining_time);
 
-            // If the process is not finished, promote it to a lower priority queue
-            if process.remaining_time > 0 {
+            if process.remaining_time == 0 {
+                // Completed processes are not re-added to any queue
+                return;
+            }
+
+            if process.allotment_used >= self.allotment[queue_index] {
+                // Allotment exhausted: demote to the next lower queue
+                process.allotment_used = 0;
                 if queue_index + 1 < self.num_levels {
                     process.priority += 1; // Increase the priority (decrease the queue index)
-                    self.queues[queue_index + 1].push(process); // Move to the next queue
+                    self.queues[queue_index + 1].push_back(process); // Move to the back of the next queue
+                    self.sync_ready_bit(queue_index + 1);
+                } else {
+                    self.queues[queue_index].push_back(process); // Already at the lowest queue
+                    self.sync_ready_bit(queue_index);
                 }
+            } else {
+                // Allotment not yet used up: round-robin within this level instead of demoting
+                self.queues[queue_index].push_back(process);
+                self.sync_ready_bit(queue_index);
             }
-            // Completed processes are not re-added to any queue
         }
     }
 
@@ -78,22 +156,196 @@ impl MLFQ {
         // Loop through all queues except the highest priority
         for queue_index in 1..self.num_levels {
             // Move each process in the current queue back to the highest priority queue
-            while let Some(mut process) = self.queues[queue_index].pop() {
+            while let Some(mut process) = self.queues[queue_index].pop_front() {
                 process.priority = 0; // Reset priority to the highest
-                self.queues[0].push(process); // Add process to the highest priority queue
+                process.allotment_used = 0; // Boosted processes start their allotment over
+                self.queues[0].push_back(process); // Add process to the highest priority queue
             }
+            self.sync_ready_bit(queue_index);
         }
+        self.sync_ready_bit(0);
     }
 
     // Update the time and trigger a priority boost if necessary
+    //
+    // Superseded by the boost check inlined into `run_until_complete`, but kept as a standalone
+    // unit-testable entry point alongside `execute_process`.
+    #[allow(dead_code)]
     pub fn update_time(&mut self, elapsed_time: u32) {
         self.current_time += elapsed_time; // Increment the current time
-        let boost_interval = 100; // Define the time interval for priority boosting
         // Check if it's time to boost priorities
-        if self.current_time % boost_interval == 0 {
+        if self.current_time.is_multiple_of(BOOST_INTERVAL) {
             self.priority_boost(); // Call the priority boost function
         }
     }
+
+    // Drive a full workload to completion. Processes are admitted into the top queue once their
+    // `arrival_time` is reached (checked between dispatches, so a process arriving mid-quantum
+    // is picked up as soon as the running one yields rather than interrupting it); the highest
+    // non-empty priority queue is selected and its head process runs for one full `time_quanta`
+    // slice -- or less, if it finishes first -- before the usual allotment/demotion bookkeeping
+    // is applied, exactly mirroring `execute_process`/`Scheduler::quantum_for` so the per-level
+    // quantum actually matters here too. `first_run_time` and `completion_time` are recorded as
+    // they become known, and the periodic priority boost keeps running throughout. Returns the
+    // processes in completion order; the same data is retained internally for `metrics()`.
+    pub fn run_until_complete(&mut self, processes: Vec<Process>) -> &[Process] {
+        let mut pending = processes;
+        pending.sort_by_key(|p| p.arrival_time);
+        let mut pending: VecDeque<Process> = pending.into();
+
+        loop {
+            // Admit every process whose arrival time has been reached into the top queue
+            while pending.front().is_some_and(|p| p.arrival_time <= self.current_time) {
+                let mut process = pending.pop_front().unwrap();
+                process.priority = 0;
+                if process.remaining_time == 0 {
+                    // A zero-burst process completes on admission -- it never actually runs, so
+                    // there's nothing to decrement and no queue for it to sit in.
+                    process.first_run_time = Some(self.current_time);
+                    process.completion_time = Some(self.current_time);
+                    self.completed.push(process);
+                    continue;
+                }
+                self.queues[0].push_back(process);
+                self.sync_ready_bit(0);
+            }
+
+            let level = match self.highest_ready_level() {
+                Some(level) => level,
+                None => {
+                    if pending.is_empty() {
+                        break; // Nothing ready and nothing left to arrive: simulation is done
+                    }
+                    // Nothing is ready yet: jump ahead to the next arrival instead of idling
+                    self.current_time = pending.front().unwrap().arrival_time;
+                    continue;
+                }
+            };
+
+            let mut process = self.queues[level].pop_front().unwrap();
+            self.sync_ready_bit(level);
+            if process.first_run_time.is_none() {
+                process.first_run_time = Some(self.current_time);
+            }
+
+            let time_before = self.current_time;
+            let executed_time = process.remaining_time.min(self.time_quanta[level]);
+            process.remaining_time -= executed_time;
+            process.total_executed_time += executed_time;
+            process.allotment_used += executed_time;
+            self.current_time += executed_time;
+
+            if process.remaining_time == 0 {
+                process.completion_time = Some(self.current_time);
+                self.completed.push(process);
+            } else if process.allotment_used >= self.allotment[level] {
+                process.allotment_used = 0;
+                if level + 1 < self.num_levels {
+                    process.priority = level + 1;
+                    self.queues[level + 1].push_back(process);
+                    self.sync_ready_bit(level + 1);
+                } else {
+                    self.queues[level].push_back(process);
+                    self.sync_ready_bit(level);
+                }
+            } else {
+                self.queues[level].push_back(process);
+                self.sync_ready_bit(level);
+            }
+
+            // A dispatch can now advance several ticks at once, so a boost interval boundary may
+            // fall strictly between `time_before` and the new `current_time` rather than land on
+            // it exactly -- compare how many boundaries each has passed rather than checking
+            // divisibility of `current_time` alone.
+            if self.current_time / BOOST_INTERVAL > time_before / BOOST_INTERVAL {
+                self.priority_boost();
+            }
+        }
+
+        &self.completed
+    }
+
+    // Compute turnaround, waiting and response times for every process that `run_until_complete`
+    // has finished so far, along with their averages across the workload.
+    pub fn metrics(&self) -> Metrics {
+        let per_process: Vec<ProcessMetrics> = self.completed.iter().map(|p| {
+            let completion_time = p.completion_time.expect("completed process must have a completion time");
+            let first_run_time = p.first_run_time.expect("completed process must have run at least once");
+            let turnaround_time = completion_time - p.arrival_time;
+            let waiting_time = turnaround_time - p.total_executed_time;
+            let response_time = first_run_time - p.arrival_time;
+
+            ProcessMetrics { id: p.id, turnaround_time, waiting_time, response_time }
+        }).collect();
+
+        let count = per_process.len() as f64;
+        let average = |sum: u32| if count > 0.0 { sum as f64 / count } else { 0.0 };
+
+        let average_turnaround_time = average(per_process.iter().map(|m| m.turnaround_time).sum());
+        let average_waiting_time = average(per_process.iter().map(|m| m.waiting_time).sum());
+        let average_response_time = average(per_process.iter().map(|m| m.response_time).sum());
+
+        Metrics { per_process, average_turnaround_time, average_waiting_time, average_response_time }
+    }
+}
+
+// MLFQ is one implementor of the common `Scheduler` interface (see scheduler.rs), so it can be
+// run through the same generic `dispatch` loop as RoundRobin, Fcfs and Sjf. The quantum and
+// allotment/demotion bookkeeping mirror `execute_process`, just split across `quantum_for` and
+// `on_quantum_expired` so the actual execution step lives in the shared driver instead of here.
+impl Scheduler for MLFQ {
+    fn add_process(&mut self, process: Process) {
+        MLFQ::add_process(self, process);
+    }
+
+    fn next(&mut self) -> Option<Process> {
+        let level = self.highest_ready_level()?;
+        let process = self.queues[level].pop_front();
+        self.sync_ready_bit(level);
+        process
+    }
+
+    fn quantum_for(&self, process: &Process) -> u32 {
+        self.time_quanta[process.priority]
+    }
+
+    fn on_quantum_expired(&mut self, mut process: Process) {
+        let level = process.priority;
+        process.allotment_used += self.time_quanta[level];
+
+        if process.allotment_used >= self.allotment[level] {
+            process.allotment_used = 0;
+            if level + 1 < self.num_levels {
+                process.priority = level + 1;
+                self.queues[level + 1].push_back(process);
+                self.sync_ready_bit(level + 1);
+            } else {
+                self.queues[level].push_back(process);
+                self.sync_ready_bit(level);
+            }
+        } else {
+            self.queues[level].push_back(process);
+            self.sync_ready_bit(level);
+        }
+    }
+}
+
+// Per-process scheduling quality measurements produced by `MLFQ::metrics`
+#[derive(Debug, PartialEq)]
+pub struct ProcessMetrics {
+    pub id: u32,
+    pub turnaround_time: u32,           // completion_time - arrival_time
+    pub waiting_time: u32,              // turnaround_time - total_executed_time
+    pub response_time: u32,             // first_run_time - arrival_time
+}
+
+// Aggregate scheduling quality report produced by `MLFQ::metrics`
+#[derive(Debug, PartialEq)]
+pub struct Metrics {
+    pub per_process: Vec<ProcessMetrics>,
+    pub average_turnaround_time: f64,
+    pub average_waiting_time: f64,
+    pub average_response_time: f64,
 }
 
 // Automated test cases for the MLFQ scheduling system
@@ -101,20 +353,41 @@ impl MLFQ {
 mod tests {
     use super::*;
 
+    fn new_process(id: u32, priority: usize, remaining_time: u32, total_executed_time: u32) -> Process {
+        Process {
+            id, priority, remaining_time, total_executed_time,
+            allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
+    fn process_with_allotment_used(id: u32, priority: usize, remaining_time: u32, allotment_used: u32) -> Process {
+        Process {
+            id, priority, remaining_time, total_executed_time: allotment_used,
+            allotment_used, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
+    fn arriving_process(id: u32, arrival_time: u32, remaining_time: u32) -> Process {
+        Process {
+            id, priority: 0, remaining_time, total_executed_time: 0,
+            allotment_used: 0, arrival_time, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
     #[test]
     fn test_add_process() {
-        let mut mlfq = MLFQ::new(3, vec![2, 4, 8]);
-        
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+
         // Create sample processes for testing
-        let process1 = Process { id: 1, priority: 0, remaining_time: 10, total_executed_time: 0 };
-        let process2 = Process { id: 2, priority: 1, remaining_time: 5, total_executed_time: 0 };
-        let process3 = Process { id: 3, priority: 5, remaining_time: 8, total_executed_time: 0 };
-        
+        let process1 = new_process(1, 0, 10, 0);
+        let process2 = new_process(2, 1, 5, 0);
+        let process3 = new_process(3, 5, 8, 0);
+
         // Add processes to the MLFQ
         mlfq.add_process(process1);
         mlfq.add_process(process2);
         mlfq.add_process(process3);
-        
+
         // Verify the correct distribution of processes across queues
         assert_eq!(mlfq.queues[0].len(), 1);
         assert_eq!(mlfq.queues[1].len(), 1);
@@ -122,48 +395,199 @@ mod tests {
     }
 
     #[test]
-    fn test_execute_process() {
-        let mut mlfq = MLFQ::new(3, vec![2, 4, 8]);
-        // Add a process to the highest priority queue
-        mlfq.queues[0].push(Process { id: 1, priority: 0, remaining_time: 5, total_executed_time: 0 });
-        
-        // Execute the process
+    fn test_execute_process_is_fifo() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        // Two processes arrive at the same level; the first one in should be the first one run
+        mlfq.queues[0].push_back(new_process(1, 0, 5, 0));
+        mlfq.queues[0].push_back(new_process(2, 0, 5, 0));
+
+        mlfq.execute_process(0);
+
+        // Process 1 ran first (FIFO order) and, having used only half its allotment, rotates
+        // to the back of the same queue rather than being demoted
+        assert_eq!(mlfq.queues[0][0].id, 2);
+        assert_eq!(mlfq.queues[0][1].id, 1);
+        assert_eq!(mlfq.queues[1].len(), 0);
+    }
+
+    #[test]
+    fn test_execute_process_round_robins_within_allotment() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        mlfq.queues[0].push_back(new_process(1, 0, 6, 0));
+
+        // Allotment at level 0 is 4; a single 2-unit quantum doesn't exhaust it
+        mlfq.execute_process(0);
+
+        assert_eq!(mlfq.queues[0].len(), 1);
+        assert_eq!(mlfq.queues[1].len(), 0);
+        assert_eq!(mlfq.queues[0][0].allotment_used, 2);
+    }
+
+    #[test]
+    fn test_execute_process_demotes_once_allotment_exhausted() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        mlfq.queues[0].push_back(process_with_allotment_used(1, 0, 10, 2));
+
+        // Allotment at level 0 is 4 and 2 units are already used; this quantum pushes it to 4
         mlfq.execute_process(0);
-        
-        // Check the state of the queues after execution
-        assert_eq!(mlfq.queues[0].len(), 0); // The process should have been removed from the queue
-        assert_eq!(mlfq.queues[1].len(), 1); // The process should now be in the next queue
-        assert_eq!(mlfq.queues[1][0].remaining_time, 3); // Check remaining time
-        assert_eq!(mlfq.queues[1][0].total_executed_time, 2); // Check total executed time
+
+        assert_eq!(mlfq.queues[0].len(), 0);
+        assert_eq!(mlfq.queues[1].len(), 1);
+        assert_eq!(mlfq.queues[1][0].allotment_used, 0); // reset on demotion
+    }
+
+    #[test]
+    fn test_execute_process_gaming_does_not_avoid_demotion() {
+        // Even though each call below only ever runs a single quantum (2 units) and the
+        // process always has work left over, the cumulative allotment still triggers a
+        // demotion once it reaches the per-level allotment (4) -- accounting is driven by
+        // total CPU consumed at this level, not by how it was split across dispatches.
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        mlfq.queues[0].push_back(new_process(1, 0, 10, 0));
+
+        mlfq.execute_process(0); // allotment_used: 0 -> 2
+        assert_eq!(mlfq.queues[0].len(), 1);
+        assert_eq!(mlfq.queues[1].len(), 0);
+
+        mlfq.execute_process(0); // allotment_used: 2 -> 4, allotment exhausted: demote
+        assert_eq!(mlfq.queues[0].len(), 0);
+        assert_eq!(mlfq.queues[1].len(), 1);
     }
 
     #[test]
     fn test_priority_boost() {
-        let mut mlfq = MLFQ::new(3, vec![2, 4, 8]);
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
         // Add processes to lower priority queues
-        mlfq.queues[1].push(Process { id: 1, priority: 1, remaining_time: 5, total_executed_time: 3 });
-        mlfq.queues[2].push(Process { id: 2, priority: 2, remaining_time: 3, total_executed_time: 7 });
-        
+        mlfq.queues[1].push_back(new_process(1, 1, 5, 3));
+        mlfq.queues[2].push_back(new_process(2, 2, 3, 7));
+
         // Update time to trigger a priority boost
         mlfq.update_time(100);
-        
+
         // Verify that processes have been boosted to the highest priority queue
         assert_eq!(mlfq.queues[0].len(), 2);
         assert_eq!(mlfq.queues[1].len(), 0);
         assert_eq!(mlfq.queues[2].len(), 0);
+        assert!(mlfq.queues[0].iter().all(|p| p.allotment_used == 0));
     }
 
     #[test]
     fn test_boost_does_not_occur_prematurely() {
-        let mut mlfq = MLFQ::new(3, vec![2, 4, 8]);
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
         // Add a process to the lower priority queue
-        mlfq.queues[1].push(Process { id: 1, priority: 1, remaining_time: 5, total_executed_time: 3 });
-        
+        mlfq.queues[1].push_back(new_process(1, 1, 5, 3));
+
         // Update time without reaching the boost interval
         mlfq.update_time(50);
-        
+
         // Check that no boost has occurred
         assert_eq!(mlfq.queues[1].len(), 1); // Process should still be in queue 1
         assert_eq!(mlfq.queues[0].len(), 0); // Queue 0 should remain empty
     }
+
+    #[test]
+    fn test_run_until_complete_single_process() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        let processes = vec![arriving_process(1, 0, 3)];
+
+        let completed = mlfq.run_until_complete(processes);
+
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].completion_time, Some(3));
+        assert_eq!(completed[0].first_run_time, Some(0));
+    }
+
+    #[test]
+    fn test_run_until_complete_admits_later_arrivals() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        // Process 2 arrives after process 1 has already finished
+        let processes = vec![arriving_process(1, 0, 2), arriving_process(2, 5, 2)];
+
+        let completed = mlfq.run_until_complete(processes);
+
+        assert_eq!(completed.len(), 2);
+        assert_eq!(completed[0].id, 1);
+        assert_eq!(completed[0].completion_time, Some(2));
+        assert_eq!(completed[1].id, 2);
+        assert_eq!(completed[1].first_run_time, Some(5)); // CPU sat idle until process 2 arrived
+        assert_eq!(completed[1].completion_time, Some(7));
+    }
+
+    #[test]
+    fn test_metrics_computes_turnaround_waiting_response() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        let processes = vec![arriving_process(1, 0, 3)];
+
+        mlfq.run_until_complete(processes);
+        let metrics = mlfq.metrics();
+
+        assert_eq!(metrics.per_process.len(), 1);
+        let p = &metrics.per_process[0];
+        assert_eq!(p.turnaround_time, 3); // completion(3) - arrival(0)
+        assert_eq!(p.waiting_time, 0);    // turnaround(3) - executed(3)
+        assert_eq!(p.response_time, 0);   // first_run(0) - arrival(0)
+        assert_eq!(metrics.average_turnaround_time, 3.0);
+        assert_eq!(metrics.average_waiting_time, 0.0);
+        assert_eq!(metrics.average_response_time, 0.0);
+    }
+
+    #[test]
+    fn test_metrics_reflects_waiting_behind_another_process() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        // Both ready at time 0; process 1 (queued first) delays process 2's start
+        let processes = vec![arriving_process(1, 0, 4), arriving_process(2, 0, 2)];
+
+        mlfq.run_until_complete(processes);
+        let metrics = mlfq.metrics();
+
+        let p2 = metrics.per_process.iter().find(|m| m.id == 2).unwrap();
+        assert!(p2.waiting_time > 0);
+        assert!(p2.response_time > 0);
+    }
+
+    #[test]
+    fn test_highest_ready_level_empty() {
+        let mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        assert_eq!(mlfq.highest_ready_level(), None);
+    }
+
+    #[test]
+    fn test_highest_ready_level_skips_empty_levels() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        mlfq.add_process(new_process(1, 2, 5, 0));
+        assert_eq!(mlfq.highest_ready_level(), Some(2));
+
+        mlfq.add_process(new_process(2, 0, 5, 0));
+        assert_eq!(mlfq.highest_ready_level(), Some(0));
+    }
+
+    #[test]
+    fn test_highest_ready_level_updates_after_execute() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        mlfq.queues[0].push_back(new_process(1, 0, 2, 0));
+        mlfq.sync_ready_bit(0);
+
+        mlfq.execute_process(0); // finishes: queue 0 empties
+        assert_eq!(mlfq.highest_ready_level(), None);
+    }
+
+    #[test]
+    fn test_mlfq_as_scheduler_demotes_via_dispatch() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![2, 4, 8]);
+        Scheduler::add_process(&mut mlfq, new_process(1, 0, 10, 0));
+
+        // Allotment at level 0 equals the quantum, so a single dispatch exhausts it and demotes
+        assert!(crate::scheduler::dispatch(&mut mlfq).is_none());
+        assert_eq!(mlfq.queues[0].len(), 0);
+        assert_eq!(mlfq.queues[1].len(), 1);
+    }
+
+    #[test]
+    fn test_mlfq_as_scheduler_returns_completed_process() {
+        let mut mlfq = MLFQ::new(3, vec![2, 4, 8], vec![4, 8, 16]);
+        Scheduler::add_process(&mut mlfq, new_process(1, 0, 2, 0));
+
+        let completed = crate::scheduler::dispatch(&mut mlfq).expect("process should have completed");
+        assert_eq!(completed.id, 1);
+    }
 }
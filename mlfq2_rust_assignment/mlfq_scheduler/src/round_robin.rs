@@ -0,0 +1,67 @@
+// A classic round-robin scheduler: every process shares one FIFO queue and runs for the same
+// fixed quantum, cycling to the back of the queue if it hasn't finished.
+use std::collections::VecDeque;
+
+use crate::mlfq::Process;
+use crate::scheduler::Scheduler;
+
+pub struct RoundRobin {
+    queue: VecDeque<Process>,
+    quantum: u32,
+}
+
+impl RoundRobin {
+    pub fn new(quantum: u32) -> Self {
+        RoundRobin { queue: VecDeque::new(), quantum }
+    }
+}
+
+impl Scheduler for RoundRobin {
+    fn add_process(&mut self, process: Process) {
+        self.queue.push_back(process);
+    }
+
+    fn next(&mut self) -> Option<Process> {
+        self.queue.pop_front()
+    }
+
+    fn quantum_for(&self, _process: &Process) -> u32 {
+        self.quantum
+    }
+
+    fn on_quantum_expired(&mut self, process: Process) {
+        self.queue.push_back(process); // rotate to the back, same as everyone else
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::dispatch;
+
+    fn process(id: u32, remaining_time: u32) -> Process {
+        Process {
+            id, priority: 0, remaining_time, total_executed_time: 0,
+            allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
+    #[test]
+    fn test_round_robin_cycles_unfinished_processes_to_the_back() {
+        let mut rr = RoundRobin::new(2);
+        rr.add_process(process(1, 5));
+        rr.add_process(process(2, 5));
+
+        assert!(dispatch(&mut rr).is_none()); // runs process 1 for one quantum
+        assert_eq!(rr.next().unwrap().id, 2); // process 2 now runs next, not process 1 again
+    }
+
+    #[test]
+    fn test_round_robin_returns_completed_process() {
+        let mut rr = RoundRobin::new(4);
+        rr.add_process(process(1, 3));
+
+        let completed = dispatch(&mut rr).expect("process should have completed");
+        assert_eq!(completed.id, 1);
+    }
+}
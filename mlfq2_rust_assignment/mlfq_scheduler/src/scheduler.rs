@@ -0,0 +1,78 @@
+// Common interface all scheduling policies implement, so a single driver loop can run MLFQ,
+// RoundRobin, Fcfs or Sjf over the same workload and compare them. Modeled on how blk-mq lets
+// multiple I/O schedulers (BFQ, Kyber, deadline) plug into one framework through a shared
+// interface instead of hard-wiring one policy into the dispatch loop.
+use crate::mlfq::Process;
+
+pub trait Scheduler {
+    // Admit a new process into the scheduler
+    fn add_process(&mut self, process: Process);
+
+    // Remove and return the next process to run, if any is ready
+    fn next(&mut self) -> Option<Process>;
+
+    // The time quantum this scheduler wants to run `process` for. Non-preemptive policies
+    // (Fcfs, Sjf) return u32::MAX so the process always runs to completion in one dispatch.
+    fn quantum_for(&self, process: &Process) -> u32;
+
+    // Called when `process` has run for a quantum but still has work left; the scheduler
+    // decides where it goes next (e.g. MLFQ may demote it, RoundRobin rotates it to the back).
+    fn on_quantum_expired(&mut self, process: Process);
+}
+
+// Run whichever process `scheduler` selects next for one quantum-sized step. Returns the
+// process if this step completed it; a still-running process is handed back to the scheduler
+// via `on_quantum_expired` and `None` is returned. Because this only depends on the
+// `Scheduler` trait, the same loop drives MLFQ, RoundRobin, Fcfs and Sjf interchangeably.
+pub fn dispatch<S: Scheduler + ?Sized>(scheduler: &mut S) -> Option<Process> {
+    let mut process = scheduler.next()?;
+    let quantum = scheduler.quantum_for(&process);
+    let executed_time = process.remaining_time.min(quantum);
+
+    process.remaining_time -= executed_time;
+    process.total_executed_time += executed_time;
+
+    if process.remaining_time == 0 {
+        Some(process)
+    } else {
+        scheduler.on_quantum_expired(process);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::round_robin::RoundRobin;
+
+    fn process(id: u32, remaining_time: u32) -> Process {
+        Process {
+            id, priority: 0, remaining_time, total_executed_time: 0,
+            allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
+    #[test]
+    fn test_dispatch_returns_none_while_process_still_has_work() {
+        let mut rr = RoundRobin::new(2);
+        rr.add_process(process(1, 5));
+
+        assert!(dispatch(&mut rr).is_none());
+    }
+
+    #[test]
+    fn test_dispatch_returns_completed_process() {
+        let mut rr = RoundRobin::new(4);
+        rr.add_process(process(1, 3));
+
+        let completed = dispatch(&mut rr).expect("process should have completed");
+        assert_eq!(completed.id, 1);
+        assert_eq!(completed.remaining_time, 0);
+    }
+
+    #[test]
+    fn test_dispatch_returns_none_when_scheduler_is_empty() {
+        let mut rr = RoundRobin::new(2);
+        assert!(dispatch(&mut rr).is_none());
+    }
+}
@@ -0,0 +1,73 @@
+// Shortest job first: among all ready processes, always run the one with the least remaining
+// time next, to completion, with no preemption.
+use crate::mlfq::Process;
+use crate::scheduler::Scheduler;
+
+pub struct Sjf {
+    ready: Vec<Process>,
+}
+
+impl Sjf {
+    pub fn new() -> Self {
+        Sjf { ready: Vec::new() }
+    }
+}
+
+impl Default for Sjf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler for Sjf {
+    fn add_process(&mut self, process: Process) {
+        self.ready.push(process);
+    }
+
+    fn next(&mut self) -> Option<Process> {
+        let shortest_index = self.ready.iter()
+            .enumerate()
+            .min_by_key(|(_, p)| p.remaining_time)
+            .map(|(index, _)| index)?;
+        Some(self.ready.remove(shortest_index))
+    }
+
+    fn quantum_for(&self, _process: &Process) -> u32 {
+        u32::MAX // non-preemptive: always run the chosen process to completion
+    }
+
+    fn on_quantum_expired(&mut self, process: Process) {
+        // quantum_for never lets a process run out of time, so this should be unreachable;
+        // if it ever is, put it back so it's reconsidered for the next pick.
+        self.ready.push(process);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::dispatch;
+
+    fn process(id: u32, remaining_time: u32) -> Process {
+        Process {
+            id, priority: 0, remaining_time, total_executed_time: 0,
+            allotment_used: 0, arrival_time: 0, completion_time: None, first_run_time: None, accumulated_priority: 0, priority_credit: 0,
+        }
+    }
+
+    #[test]
+    fn test_sjf_picks_shortest_remaining_time_first() {
+        let mut sjf = Sjf::new();
+        sjf.add_process(process(1, 10)); // longer job, queued first
+        sjf.add_process(process(2, 1));  // shorter job, queued second
+
+        let completed = dispatch(&mut sjf).expect("process should have completed");
+        assert_eq!(completed.id, 2); // the shorter job runs first despite arriving later
+    }
+
+    #[test]
+    fn test_sjf_empty_returns_none() {
+        let mut sjf = Sjf::new();
+        assert!(sjf.next().is_none());
+    }
+}